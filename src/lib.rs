@@ -4,8 +4,12 @@
 //!
 //! Most clients won't need to use any of the `SRCINFO_*` constants, but instead should use the
 //! [`SrcInfo`] struct to read a `.SRCINFO` file.
+use digest::Digest;
 use regex::Regex;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
 
 // Python bindings.
 mod python;
@@ -81,9 +85,59 @@ pub struct ParserError {
 
 type ParseMap = HashMap<String, Vec<String>>;
 
+/// Insert a value for `key` into `map`, appending to any values already present under that key.
+fn push_value(map: &mut ParseMap, key: &str, value: String) {
+    if let Some(values) = map.get_mut(key) {
+        values.push(value);
+    } else {
+        map.insert(key.to_owned(), vec![value]);
+    }
+}
+
 #[derive(Debug)]
 pub struct SrcInfo {
     map: ParseMap,
+    base: ParseMap,
+    packages: Vec<ParseMap>,
+}
+
+/// A struct representing a single section of a `.SRCINFO` file: either the `pkgbase` section (see
+/// [`SrcInfo::base`]) or the effective, merged section for one `pkgname` entry (see
+/// [`SrcInfo::packages`]).
+#[derive(Debug)]
+pub struct Package {
+    map: ParseMap,
+}
+
+impl Package {
+    /// Get a value for anything that's a string variable in a PKGBUILD.
+    ///
+    /// **Note** that you'll need to use [`Package::get_array`] if you want to get the `pkgname` variable, since that has the
+    /// ability to be more than one item.
+    ///
+    /// Returns the [`Some`] variant if the variable can be found, otherwise the [`None`] variant is returned.
+    pub fn get_string(&self, key: &str) -> Option<&String> {
+        if !SRCINFO_STRINGS.contains(&SrcInfo::get_base_key(key)) {
+            return None;
+        }
+
+        if let Some(values) = self.map.get(&key.to_owned()) {
+            Some(&values[0])
+        } else {
+            None
+        }
+    }
+
+    /// Get a value for anything that's an array variable in a PKGBUILD.
+    ///
+    /// Returns the [`Some`] variant if the variable can be found, otherwise the [`None`] variant is returned.
+    pub fn get_array(&self, key: &str) -> Option<&Vec<String>> {
+        if !SRCINFO_ARRAYS.contains(&SrcInfo::get_base_key(key)) {
+            return None;
+        }
+
+        self.map.get(&key.to_owned())
+    }
 }
 
 impl SrcInfo {
@@ -93,6 +147,9 @@ impl SrcInfo {
     /// `content` should be a string representing the content of the `.SRCINFO` file.
     pub fn new(content: &str) -> Result<Self, ParserError> {
         let mut map: ParseMap = HashMap::new();
+        let mut base: ParseMap = HashMap::new();
+        let mut packages: Vec<ParseMap> = Vec::new();
+        let mut current: Option<ParseMap> = None;
 
         for (_index, _line) in content.lines().enumerate() {
             let mut line = _line.to_owned();
@@ -131,11 +188,39 @@ impl SrcInfo {
             let key = parts[0].to_string();
             let value = parts[1..].join(" = ");
 
-            if let Some(values) = map.get_mut(&key) {
-                values.push(value);
-            } else {
-                map.insert(key, vec![value]);
+            // A `pkgname` line starts a new per-package section; everything before the first one
+            // belongs to the `pkgbase` section instead.
+            if key == "pkgname" {
+                if let Some(pkg) = current.take() {
+                    packages.push(pkg);
+                }
+                current = Some(HashMap::new());
             }
+
+            // A string field may be set in the `pkgbase` section and again in each `pkgname`
+            // section, so only its first value goes into the flattened map (which keeps the
+            // duplicate check below from rejecting such overrides). A string field repeated
+            // within one `pkgname` section is rejected here instead.
+            let package_string = current.is_some() && SRCINFO_STRINGS.contains(&key.as_str());
+
+            if package_string && current.as_ref().unwrap().contains_key(&key) {
+                return Err(ParserError {
+                    msg: format!(
+                        "Key '{}' is present more than once when it is not allowed to.",
+                        key
+                    ),
+                    line_num: Some(index),
+                });
+            }
+
+            if !package_string || !map.contains_key(&key) {
+                push_value(&mut map, &key, value.clone());
+            }
+            push_value(current.as_mut().unwrap_or(&mut base), &key, value);
+        }
+
+        if let Some(pkg) = current.take() {
+            packages.push(pkg);
         }
 
         // Make sure we have all required keys present.
@@ -164,7 +249,46 @@ impl SrcInfo {
             }
         }
 
-        Ok(Self { map })
+        Ok(Self {
+            map,
+            base,
+            packages,
+        })
+    }
+
+    /// Get the `pkgbase`-level fields of this `.SRCINFO` file, ignoring any per-`pkgname`
+    /// overrides.
+    pub fn base(&self) -> Package {
+        Package {
+            map: self.base.clone(),
+        }
+    }
+
+    /// Get one [`Package`] per `pkgname` section in this `.SRCINFO` file, with each package's
+    /// fields merged against [`SrcInfo::base`]: a field present in the package section overrides
+    /// the base, an array field left unmentioned in the package section inherits the base's
+    /// values, and an array field explicitly emptied in the package section (i.e. present with a
+    /// single empty value) suppresses inheritance entirely.
+    pub fn packages(&self) -> Vec<Package> {
+        self.packages
+            .iter()
+            .map(|pkg| {
+                let mut map = self.base.clone();
+
+                for (key, values) in pkg {
+                    if SRCINFO_ARRAYS.contains(&SrcInfo::get_base_key(key))
+                        && values.len() == 1
+                        && values[0].is_empty()
+                    {
+                        map.insert(key.clone(), Vec::new());
+                    } else {
+                        map.insert(key.clone(), values.clone());
+                    }
+                }
+
+                Package { map }
+            })
+            .collect()
     }
 
     /// Convert an extended string to it's base form.
@@ -258,4 +382,620 @@ impl SrcInfo {
             Some(matches)
         }
     }
+
+    /// Build the distro/arch extended variants of `base_key`, ordered from least to most specific
+    /// (`distro_key`, `key_arch`, `distro_key_arch`). Variants are omitted if `distro`/`arch`
+    /// aren't given.
+    fn extension_keys(base_key: &str, distro: Option<&str>, arch: Option<&str>) -> Vec<String> {
+        let mut keys = Vec::new();
+
+        if let Some(distro) = distro {
+            keys.push(format!("{}_{}", distro, base_key));
+        }
+
+        if let Some(arch) = arch {
+            keys.push(format!("{}_{}", base_key, arch));
+        }
+
+        if let (Some(distro), Some(arch)) = (distro, arch) {
+            keys.push(format!("{}_{}_{}", distro, base_key, arch));
+        }
+
+        keys
+    }
+
+    /// Resolve the single effective value of `key` for a concrete `distro`/`arch` target.
+    ///
+    /// For string variables, the most specific variant present wins, in the order
+    /// `distro_key_arch` > `key_arch` > `distro_key` > bare `key`. For array variables, the base
+    /// array and every matching extension are concatenated together, since architecture- and
+    /// distro-specific dependencies add to the general ones rather than replacing them.
+    ///
+    /// Returns the [`None`] variant if `key` isn't a key makedeb supports extending, or if none of
+    /// the candidate variants are present in the `.SRCINFO` file.
+    pub fn resolve(
+        &self,
+        key: &str,
+        distro: Option<&str>,
+        arch: Option<&str>,
+    ) -> Option<ResolvedValue> {
+        let base_key = SrcInfo::get_base_key(key);
+
+        if !SRCINFO_EXTENDED.contains(&base_key) {
+            return None;
+        }
+
+        if SRCINFO_STRINGS.contains(&base_key) {
+            let mut candidates = SrcInfo::extension_keys(base_key, distro, arch);
+            candidates.reverse();
+            candidates.push(base_key.to_owned());
+
+            candidates
+                .iter()
+                .find_map(|candidate| self.get_string(candidate))
+                .map(|value| ResolvedValue::String(value.clone()))
+        } else {
+            let mut values = Vec::new();
+            let mut found = false;
+
+            if let Some(base_values) = self.get_array(base_key) {
+                values.extend(base_values.iter().cloned());
+                found = true;
+            }
+
+            for candidate in SrcInfo::extension_keys(base_key, distro, arch) {
+                if let Some(extra_values) = self.get_array(&candidate) {
+                    values.extend(extra_values.iter().cloned());
+                    found = true;
+                }
+            }
+
+            found.then_some(ResolvedValue::Array(values))
+        }
+    }
+
+    /// Render this `.SRCINFO` file as a Debian binary `control` stanza.
+    ///
+    /// Maps `pkgname` to `Package`, `pkgdesc` to `Description`, `pkgver`/`pkgrel`/`epoch` to
+    /// `Version`, and `arch` to `Architecture`. `depends`, `conflicts`, `provides`, and `replaces`
+    /// are mapped to their corresponding control fields, with each dependency atom run through
+    /// [`SplitDependency::as_control`]; `optdepends` maps to `Suggests` the same way, after
+    /// stripping each entry's `: reason` suffix. `makedepends` is a build-time dependency and has
+    /// no corresponding field in a binary control stanza, so it's left out. Any `control_fields`
+    /// entries (`Key: Value` lines) are applied last, overriding a field of the same name if one
+    /// was already generated.
+    pub fn as_control(&self) -> String {
+        let mut fields: Vec<(String, String)> = Vec::new();
+
+        if let Some(pkgname) = self.get_array("pkgname").and_then(|values| values.first()) {
+            fields.push(("Package".to_owned(), pkgname.clone()));
+        }
+
+        if let Some(pkgdesc) = self.get_string("pkgdesc") {
+            fields.push(("Description".to_owned(), pkgdesc.clone()));
+        }
+
+        if let Some(pkgver) = self.get_string("pkgver") {
+            let mut version = pkgver.clone();
+
+            if let Some(epoch) = self.get_string("epoch") {
+                version = format!("{}:{}", epoch, version);
+            }
+
+            if let Some(pkgrel) = self.get_string("pkgrel") {
+                version = format!("{}-{}", version, pkgrel);
+            }
+
+            fields.push(("Version".to_owned(), version));
+        }
+
+        if let Some(arch) = self.get_array("arch") {
+            fields.push(("Architecture".to_owned(), arch.join(" ")));
+        }
+
+        // `makedepends` has no place here: it's a build-time dependency, and this is a *binary*
+        // control stanza.
+        let dependency_fields = [
+            ("depends", "Depends"),
+            ("conflicts", "Conflicts"),
+            ("provides", "Provides"),
+            ("replaces", "Replaces"),
+        ];
+
+        for (key, control_key) in dependency_fields {
+            if let Some(values) = self.get_array(key) {
+                let rendered = values
+                    .iter()
+                    .map(|atom| SplitDependency::new(atom).as_control())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                fields.push((control_key.to_owned(), rendered));
+            }
+        }
+
+        // `optdepends` entries are `pkg: reason` strings rather than bare dependency atoms, so the
+        // `: reason` part has to be stripped before parsing the atom itself.
+        if let Some(values) = self.get_array("optdepends") {
+            let rendered = values
+                .iter()
+                .map(|entry| {
+                    let atom = match entry.split_once(':') {
+                        Some((atom, _reason)) => atom,
+                        None => entry,
+                    };
+
+                    SplitDependency::new(atom.trim()).as_control()
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            fields.push(("Suggests".to_owned(), rendered));
+        }
+
+        if let Some(control_fields) = self.get_array("control_fields") {
+            for entry in control_fields {
+                let Some((key, value)) = entry.split_once(':') else {
+                    continue;
+                };
+
+                let key = key.trim().to_owned();
+                let value = value.trim().to_owned();
+
+                if let Some(existing) = fields.iter_mut().find(|(field, _)| field == &key) {
+                    existing.1 = value;
+                } else {
+                    fields.push((key, value));
+                }
+            }
+        }
+
+        fields
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Start building a [`SrcInfo`] programmatically rather than by parsing one. See
+    /// [`SrcInfoBuilder`].
+    pub fn builder() -> SrcInfoBuilder {
+        SrcInfoBuilder::default()
+    }
+
+    /// Render a single `pkgbase`/`pkgname` section, with `header_key` (the section's own name
+    /// field) rendered unindented first, followed by its remaining fields in [`SRCINFO_STRINGS`]/
+    /// [`SRCINFO_ARRAYS`] order, one `key = value` line per array element.
+    fn render_section(header_key: &str, map: &ParseMap) -> String {
+        let mut lines = Vec::new();
+
+        if let Some(values) = map.get(header_key) {
+            lines.push(format!("{} = {}", header_key, values[0]));
+        }
+
+        let mut keys: Vec<&String> = map.keys().filter(|key| *key != header_key).collect();
+        keys.sort_by_key(|key| {
+            let base_key = SrcInfo::get_base_key(key);
+            let rank = SRCINFO_STRINGS
+                .iter()
+                .chain(SRCINFO_ARRAYS.iter())
+                .position(|known_key| *known_key == base_key)
+                .unwrap_or(usize::MAX);
+
+            (rank, (*key).clone())
+        });
+
+        for key in keys {
+            for value in &map[key] {
+                lines.push(format!("\t{} = {}", key, value));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl fmt::Display for SrcInfo {
+    /// Write this `.SRCINFO` file out in canonical form: the `pkgbase` section first, then each
+    /// `pkgname` section, using the exact `" = "` delimiter [`SrcInfo::new`] expects so that
+    /// `SrcInfo::new(&srcinfo.to_string())` round-trips.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", SrcInfo::render_section("pkgbase", &self.base))?;
+
+        for package in &self.packages {
+            write!(f, "\n\n{}", SrcInfo::render_section("pkgname", package))?;
+        }
+
+        writeln!(f)
+    }
+}
+
+/// A builder for constructing a [`SrcInfo`] programmatically, e.g. for package-generation tools
+/// that need to emit a `.SRCINFO` file without having parsed one first. Build up a `pkgbase`
+/// section with [`SrcInfoBuilder::base_field`], then add one or more `pkgname` sections with
+/// [`SrcInfoBuilder::package`]/[`SrcInfoBuilder::package_field`].
+#[derive(Debug, Default)]
+pub struct SrcInfoBuilder {
+    base: ParseMap,
+    packages: Vec<ParseMap>,
+}
+
+impl SrcInfoBuilder {
+    /// Set a field on the `pkgbase` section. Pass a single-item `values` for string fields and any
+    /// number of items for array fields.
+    pub fn base_field(mut self, key: &str, values: Vec<String>) -> Self {
+        self.base.insert(key.to_owned(), values);
+        self
+    }
+
+    /// Start a new `pkgname` section with the given name. Subsequent calls to
+    /// [`SrcInfoBuilder::package_field`] apply to this section until another [`package`](SrcInfoBuilder::package)
+    /// call starts the next one.
+    pub fn package(mut self, pkgname: &str) -> Self {
+        self.packages
+            .push(HashMap::from([("pkgname".to_owned(), vec![pkgname.to_owned()])]));
+        self
+    }
+
+    /// Set a field on the most recently added `pkgname` section.
+    ///
+    /// # Panics
+    /// Panics if called before [`SrcInfoBuilder::package`].
+    pub fn package_field(mut self, key: &str, values: Vec<String>) -> Self {
+        self.packages
+            .last_mut()
+            .expect("`package` must be called before `package_field`")
+            .insert(key.to_owned(), values);
+        self
+    }
+
+    /// Build the [`SrcInfo`].
+    pub fn build(self) -> SrcInfo {
+        let mut map = self.base.clone();
+
+        for package in &self.packages {
+            for (key, values) in package {
+                for value in values {
+                    push_value(&mut map, key, value.clone());
+                }
+            }
+        }
+
+        SrcInfo {
+            map,
+            base: self.base,
+            packages: self.packages,
+        }
+    }
+}
+
+/// The effective value of a `.SRCINFO` variable resolved for a concrete `distro`/`arch` target,
+/// as returned by [`SrcInfo::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedValue {
+    /// The resolved value of a string variable.
+    String(String),
+    /// The resolved value of an array variable.
+    Array(Vec<String>),
+}
+
+/// A single, parsed dependency atom from a field such as `depends` or `conflicts`, e.g.
+/// `pkg>=1.0`.
+#[derive(Debug, Clone)]
+pub struct SplitPackage {
+    /// The name of the package being depended on.
+    pub pkgname: String,
+    /// The comparison operator used against `version`, if one was given.
+    pub operator: Option<String>,
+    /// The version being compared against, if an `operator` was given.
+    pub version: Option<String>,
+}
+
+impl SplitPackage {
+    /// Parse a single dependency atom, e.g. `pkg`, `pkg=1.0`, or `pkg>=1.0`.
+    pub fn new(pkg_string: &str) -> Self {
+        let re = Regex::new("(<=|>=|=|<|>)").unwrap();
+
+        if let Some(m) = re.find(pkg_string) {
+            Self {
+                pkgname: pkg_string[..m.start()].to_owned(),
+                operator: Some(m.as_str().to_owned()),
+                version: Some(pkg_string[m.end()..].to_owned()),
+            }
+        } else {
+            Self {
+                pkgname: pkg_string.to_owned(),
+                operator: None,
+                version: None,
+            }
+        }
+    }
+
+    /// Format this dependency atom the way a Debian `control` file expects, e.g. `pkg (>= 1.0)`.
+    pub fn as_control(&self) -> String {
+        match (&self.operator, &self.version) {
+            (Some(operator), Some(version)) => {
+                format!("{} ({} {})", self.pkgname, operator, version)
+            }
+            _ => self.pkgname.clone(),
+        }
+    }
+
+    /// Check whether `installed_version` satisfies this dependency atom's version constraint,
+    /// using dpkg's version comparison algorithm (see [`compare_versions`]).
+    ///
+    /// Atoms with no `operator`/`version` (i.e. a bare `pkgname`) are satisfied by any installed
+    /// version.
+    pub fn satisfied_by(&self, installed_version: &str) -> bool {
+        let (Some(operator), Some(version)) = (&self.operator, &self.version) else {
+            return true;
+        };
+
+        let ordering = compare_versions(installed_version, version);
+
+        match operator.as_str() {
+            "<" => ordering == Ordering::Less,
+            "<=" => ordering != Ordering::Greater,
+            "=" => ordering == Ordering::Equal,
+            ">=" => ordering != Ordering::Less,
+            ">" => ordering == Ordering::Greater,
+            _ => false,
+        }
+    }
+}
+
+/// Split a Debian-style version string into its `epoch` (default `0`), `upstream_version`, and
+/// `debian_revision` (default `"0"`) components.
+fn split_version(version: &str) -> (u64, String, String) {
+    let (epoch, rest) = match version.split_once(':') {
+        Some((epoch, rest)) => (epoch.parse().unwrap_or(0), rest),
+        None => (0, version),
+    };
+
+    let (upstream, revision) = match rest.rsplit_once('-') {
+        Some((upstream, revision)) => (upstream.to_owned(), revision.to_owned()),
+        None => (rest.to_owned(), "0".to_owned()),
+    };
+
+    (epoch, upstream, revision)
+}
+
+/// Rank a single character the way dpkg's version comparison does: `~` sorts before everything
+/// (even the end of the string), the end of the string sorts before letters, letters sort before
+/// everything else, and the rest falls back to ASCII order.
+fn char_order(c: Option<char>) -> i32 {
+    match c {
+        Some('~') => -1,
+        None => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+/// Compare two runs of non-digit characters using [`char_order`].
+fn compare_non_digits(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars();
+    let mut b = b.chars();
+
+    loop {
+        let (ca, cb) = (a.next(), b.next());
+
+        if ca.is_none() && cb.is_none() {
+            return Ordering::Equal;
+        }
+
+        match char_order(ca).cmp(&char_order(cb)) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+}
+
+/// Split `s` into its leading non-digit run and the remainder.
+fn take_non_digits(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Split `s` into its leading digit run and the remainder.
+fn take_digits(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Compare two `upstream_version` or `debian_revision` strings by walking them in alternating
+/// non-digit/digit passes: non-digit runs compare via [`compare_non_digits`], digit runs compare
+/// as integers (leading zeros stripped). The first pass that differs decides the result.
+fn compare_version_component(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+
+    loop {
+        let (a_prefix, a_rest) = take_non_digits(a);
+        let (b_prefix, b_rest) = take_non_digits(b);
+
+        match compare_non_digits(a_prefix, b_prefix) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+
+        let (a_digits, a_rest) = take_digits(a_rest);
+        let (b_digits, b_rest) = take_digits(b_rest);
+
+        let a_num: u64 = a_digits.trim_start_matches('0').parse().unwrap_or(0);
+        let b_num: u64 = b_digits.trim_start_matches('0').parse().unwrap_or(0);
+
+        match a_num.cmp(&b_num) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+
+        if a_rest.is_empty() && b_rest.is_empty() {
+            return Ordering::Equal;
+        }
+
+        a = a_rest;
+        b = b_rest;
+    }
+}
+
+/// Compare two Debian-style version strings (`[epoch:]upstream_version[-debian_revision]`) using
+/// dpkg's version comparison algorithm.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (epoch_a, upstream_a, revision_a) = split_version(a);
+    let (epoch_b, upstream_b, revision_b) = split_version(b);
+
+    match epoch_a.cmp(&epoch_b) {
+        Ordering::Equal => {}
+        ordering => return ordering,
+    }
+
+    match compare_version_component(&upstream_a, &upstream_b) {
+        Ordering::Equal => {}
+        ordering => return ordering,
+    }
+
+    compare_version_component(&revision_a, &revision_b)
+}
+
+/// A full dependency field entry, i.e. an OR-group of [`SplitPackage`]s separated by `|`, such as
+/// `pkg1|pkg2>=1.0`.
+#[derive(Debug, Clone)]
+pub struct SplitDependency {
+    /// The alternatives making up this OR-group, in the order they were listed.
+    pub deps: Vec<SplitPackage>,
+}
+
+impl SplitDependency {
+    /// Parse a dependency field entry, splitting its alternatives on `|`.
+    pub fn new(dep_string: &str) -> Self {
+        Self {
+            deps: dep_string.split('|').map(SplitPackage::new).collect(),
+        }
+    }
+
+    /// Format this OR-group the way a Debian `control` file expects, e.g. `pkg1 | pkg2 (>= 1.0)`.
+    pub fn as_control(&self) -> String {
+        SplitDependency::internal_as_control(&self.deps)
+    }
+
+    /// Format a slice of [`SplitPackage`]s the way a Debian `control` file expects. This is split
+    /// out from [`SplitDependency::as_control`] so the pyo3 bindings can reuse it without having to
+    /// rebuild a [`SplitDependency`] from their own wrapper type.
+    pub(crate) fn internal_as_control(deps: &[SplitPackage]) -> String {
+        deps.iter()
+            .map(SplitPackage::as_control)
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+/// The checksum array keys a `.SRCINFO` file can pair against `source`, in the order they're
+/// checked by [`SrcInfo::verify_sources`].
+const SRCINFO_CHECKSUMS: [&str; 7] = [
+    "md5sums",
+    "sha1sums",
+    "sha224sums",
+    "sha256sums",
+    "sha384sums",
+    "sha512sums",
+    "b2sums",
+];
+
+/// The literal checksum value that tells makepkg/makedeb to skip verifying a source, e.g. for
+/// sources that aren't retrieved from a fixed URL (VCS checkouts).
+const SRCINFO_CHECKSUM_SKIP: &str = "SKIP";
+
+/// The result of verifying a single `source` entry against one of its paired checksums, as
+/// returned by [`SrcInfo::verify_sources`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceVerification {
+    /// The `source` entry that was checked.
+    pub source: String,
+    /// The checksum array the entry was paired against, e.g. `"sha256sums"`.
+    pub algorithm: String,
+    /// The checksum recorded in the `.SRCINFO` file.
+    pub expected: String,
+    /// The checksum actually computed from the file on disk, or an empty string if the file
+    /// couldn't be read.
+    pub actual: String,
+    /// Whether `expected` and `actual` matched.
+    pub passed: bool,
+}
+
+impl SrcInfo {
+    /// Verify every `source` entry in this `.SRCINFO` file against its paired checksums, reading
+    /// the source files out of `dir`.
+    ///
+    /// `source` and each configured checksum array (`md5sums`, `sha1sums`, `sha224sums`,
+    /// `sha256sums`, `sha384sums`, `sha512sums`, `b2sums`) are resolved for the given `distro`/
+    /// `arch` target via [`SrcInfo::resolve`], so e.g. `focal_sha256sums` is honored. The i-th
+    /// source is paired with the i-th entry of each resolved checksum array; entries whose
+    /// checksum is `SKIP` are omitted from the result. A source not backed by a `name::url`
+    /// rename is looked up in `dir` under the final path segment of its URL.
+    pub fn verify_sources(
+        &self,
+        dir: &Path,
+        distro: Option<&str>,
+        arch: Option<&str>,
+    ) -> Vec<SourceVerification> {
+        let Some(ResolvedValue::Array(sources)) = self.resolve("source", distro, arch) else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+
+        for algorithm in SRCINFO_CHECKSUMS {
+            let Some(ResolvedValue::Array(sums)) = self.resolve(algorithm, distro, arch) else {
+                continue;
+            };
+
+            for (source, expected) in sources.iter().zip(sums.iter()) {
+                if expected == SRCINFO_CHECKSUM_SKIP {
+                    continue;
+                }
+
+                let path = dir.join(SrcInfo::source_filename(source));
+                let actual = std::fs::read(&path)
+                    .map(|contents| SrcInfo::digest(algorithm, &contents))
+                    .unwrap_or_default();
+
+                results.push(SourceVerification {
+                    source: source.clone(),
+                    algorithm: algorithm.to_owned(),
+                    passed: actual.eq_ignore_ascii_case(expected),
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+
+        results
+    }
+
+    /// Get the on-disk filename for a `source` entry: the part before `::` for a `name::url`
+    /// rename, otherwise the final path segment of the URL (or local path).
+    fn source_filename(source: &str) -> &str {
+        let source = source.split("::").next().unwrap_or(source);
+        source.rsplit('/').next().unwrap_or(source)
+    }
+
+    /// Compute the hex-encoded digest of `contents` using the hash algorithm named by a checksum
+    /// array key, e.g. `"sha256sums"`.
+    ///
+    /// # Panics
+    /// Panics if `algorithm` isn't one of [`SRCINFO_CHECKSUMS`].
+    fn digest(algorithm: &str, contents: &[u8]) -> String {
+        let bytes: Vec<u8> = match algorithm {
+            "md5sums" => md5::Md5::digest(contents).to_vec(),
+            "sha1sums" => sha1::Sha1::digest(contents).to_vec(),
+            "sha224sums" => sha2::Sha224::digest(contents).to_vec(),
+            "sha256sums" => sha2::Sha256::digest(contents).to_vec(),
+            "sha384sums" => sha2::Sha384::digest(contents).to_vec(),
+            "sha512sums" => sha2::Sha512::digest(contents).to_vec(),
+            "b2sums" => blake2::Blake2b512::digest(contents).to_vec(),
+            _ => panic!("'{}' is not a known checksum algorithm", algorithm),
+        };
+
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
 }
@@ -1,8 +1,10 @@
 use crate::{
+    compare_versions as rust_compare_versions, ResolvedValue as RustResolvedValue,
     SplitDependency as RustSplitDependency, SplitPackage as RustSplitPackage,
     SrcInfo as RustSrcInfo,
 };
-use pyo3::{create_exception, exceptions::PyException, prelude::*};
+use pyo3::{create_exception, exceptions::PyException, prelude::*, wrap_pyfunction};
+use std::cmp::Ordering;
 
 // Exceptions
 create_exception!(
@@ -74,6 +76,34 @@ impl SrcInfo {
     pub fn get_extended_values(&self, key: String) -> Option<Vec<String>> {
         self.srcinfo.get_extended_values(&key)
     }
+
+    /// Resolve the single effective value of `key` for a concrete `distro`/`arch` target.
+    ///
+    /// Returns a :class:`str` for string variables or a :class:`list` of :class:`str` for array
+    /// variables, applying the same `distro_key_arch` > `key_arch` > `distro_key` > `key`
+    /// precedence (with arrays concatenating the base and every matching extension) that
+    /// :func:`~makedeb_srcinfo.SrcInfo.get_extended_values` leaves to the caller. Returns
+    /// :class:`None` if `key` can't be extended or has no effective value for this target.
+    pub fn resolve(
+        &self,
+        py: Python<'_>,
+        key: String,
+        distro: Option<String>,
+        arch: Option<String>,
+    ) -> Option<PyObject> {
+        match self
+            .srcinfo
+            .resolve(&key, distro.as_deref(), arch.as_deref())?
+        {
+            RustResolvedValue::String(value) => Some(value.into_py(py)),
+            RustResolvedValue::Array(values) => Some(values.into_py(py)),
+        }
+    }
+
+    /// Render this `.SRCINFO` file as a Debian binary `control` stanza.
+    pub fn as_control(&self) -> String {
+        self.srcinfo.as_control()
+    }
 }
 
 #[allow(dead_code)]
@@ -99,6 +129,12 @@ impl SplitPackage {
             version: split_pkg.version,
         }
     }
+
+    /// Check whether `installed_version` satisfies this dependency atom's version constraint.
+    pub fn satisfied_by(&self, installed_version: String) -> bool {
+        self.to_rust_split_package()
+            .satisfied_by(&installed_version)
+    }
 }
 
 impl SplitPackage {
@@ -146,10 +182,23 @@ impl SplitDependency {
     }
 }
 
+/// Compare two Debian-style version strings using dpkg's version comparison algorithm. Returns a
+/// negative number if `a` is older than `b`, zero if they're equal, or a positive number if `a` is
+/// newer than `b`.
+#[pyfunction]
+fn compare_versions(a: String, b: String) -> i32 {
+    match rust_compare_versions(&a, &b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
 #[pymodule]
 fn makedeb_srcinfo(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<SrcInfo>()?;
     m.add_class::<SplitPackage>()?;
     m.add_class::<SplitDependency>()?;
+    m.add_function(wrap_pyfunction!(compare_versions, m)?)?;
     Ok(())
 }
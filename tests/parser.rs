@@ -1,4 +1,4 @@
-use makedeb_srcinfo::SrcInfo;
+use makedeb_srcinfo::{ResolvedValue, SrcInfo};
 
 #[test]
 #[rustfmt::skip]
@@ -21,6 +21,127 @@ fn valid() {
     assert!(srcinfo.get_string("focal_postrm_amd64").unwrap() == "focal_file_amd64");
 }
 
+#[test]
+fn split_packages() {
+    let file = include_str!("files/SPLIT.SRCINFO");
+    let srcinfo = SrcInfo::new(&file).unwrap();
+
+    let base = srcinfo.base();
+    assert!(base.get_string("pkgdesc").unwrap() == "Base description");
+    assert!(base.get_array("depends").unwrap() == &vec!["dep1".to_owned(), "dep2".to_owned()]);
+
+    let packages = srcinfo.packages();
+    assert!(packages.len() == 2);
+
+    let mypkg = packages.iter().find(|p| p.get_array("pkgname").unwrap()[0] == "mypkg").unwrap();
+    // No `pkgdesc` override, so the base value is inherited.
+    assert!(mypkg.get_string("pkgdesc").unwrap() == "Base description");
+    // `depends` is overridden wholesale rather than merged with the base.
+    assert!(mypkg.get_array("depends").unwrap() == &vec!["dep3".to_owned()]);
+
+    let extra = packages.iter().find(|p| p.get_array("pkgname").unwrap()[0] == "mypkg-extra").unwrap();
+    assert!(extra.get_string("pkgdesc").unwrap() == "Extra description");
+    // An explicitly empty `depends` line suppresses inheritance from the base.
+    assert!(extra.get_array("depends").unwrap().is_empty());
+}
+
+#[test]
+fn resolve() {
+    let file = include_str!("files/RESOLVE.SRCINFO");
+    let srcinfo = SrcInfo::new(&file).unwrap();
+
+    // Arrays concatenate the base with every matching extension.
+    assert!(
+        srcinfo.resolve("depends", Some("focal"), Some("amd64")).unwrap()
+            == ResolvedValue::Array(vec![
+                "base-dep".to_owned(),
+                "focal-dep".to_owned(),
+                "amd64-dep".to_owned(),
+                "focal-amd64-dep".to_owned(),
+            ])
+    );
+
+    // Strings take the most specific variant present.
+    assert!(
+        srcinfo.resolve("postrm", Some("focal"), Some("amd64")).unwrap()
+            == ResolvedValue::String("focal_file_amd64".to_owned())
+    );
+    assert!(
+        srcinfo.resolve("postrm", None, Some("amd64")).unwrap()
+            == ResolvedValue::String("file_amd64".to_owned())
+    );
+
+    // Keys that aren't extendable, or that have no matching variant, resolve to `None`.
+    assert!(srcinfo.resolve("pkgbase", None, None).is_none());
+    assert!(srcinfo.resolve("checkdepends", Some("focal"), None).is_none());
+}
+
+#[test]
+fn as_control() {
+    let file = include_str!("files/CONTROL.SRCINFO");
+    let srcinfo = SrcInfo::new(&file).unwrap();
+
+    let expected = "Package: mypkg\n\
+                     Description: Overridden description\n\
+                     Version: 1:1.0-2\n\
+                     Architecture: amd64\n\
+                     Depends: dep1 (>= 1.0), dep2 | dep3\n\
+                     Suggests: dep4\n\
+                     Maintainer: Example <example@example.com>";
+
+    // `makedepends` has no field in a binary control stanza and must not appear.
+    assert!(!srcinfo.as_control().contains("Build-Depends"));
+    // The `optdepends` reason text is stripped before the atom is parsed.
+    assert!(!srcinfo.as_control().contains("needed for bar"));
+
+    assert!(srcinfo.as_control() == expected);
+}
+
+#[test]
+fn round_trip() {
+    let file = include_str!("files/SPLIT.SRCINFO");
+    let srcinfo = SrcInfo::new(&file).unwrap();
+
+    let rendered = srcinfo.to_string();
+    let reparsed = SrcInfo::new(&rendered).unwrap();
+
+    assert!(reparsed.base().get_string("pkgdesc") == srcinfo.base().get_string("pkgdesc"));
+    assert!(reparsed.packages().len() == srcinfo.packages().len());
+}
+
+#[test]
+fn builder() {
+    let srcinfo = SrcInfo::builder()
+        .base_field("pkgbase", vec!["mypkg".to_owned()])
+        .base_field("pkgver", vec!["1.0".to_owned()])
+        .base_field("pkgrel", vec!["1".to_owned()])
+        .base_field("arch", vec!["x86_64".to_owned()])
+        .base_field("depends", vec!["dep1".to_owned()])
+        .package("mypkg")
+        .build();
+
+    let rendered = srcinfo.to_string();
+    let reparsed = SrcInfo::new(&rendered).unwrap();
+
+    assert!(reparsed.base().get_string("pkgbase").unwrap() == "mypkg");
+    assert!(reparsed.packages().len() == 1);
+}
+
+#[test]
+fn verify_sources() {
+    let file = include_str!("files/VERIFY.SRCINFO");
+    let srcinfo = SrcInfo::new(&file).unwrap();
+
+    let results = srcinfo.verify_sources(std::path::Path::new("tests/files/checksums"), None, None);
+
+    // The `renamed.txt::...` source's checksums are both `SKIP`, so it never shows up.
+    assert!(results.len() == 2);
+    assert!(results.iter().all(|result| result.source == "source1.txt"));
+    assert!(results.iter().all(|result| result.passed));
+    assert!(results.iter().any(|result| result.algorithm == "md5sums"));
+    assert!(results.iter().any(|result| result.algorithm == "sha256sums"));
+}
+
 #[test]
 fn no_value() {
     let file = include_str!("files/NO_VALUE.SRCINFO");
@@ -1,4 +1,5 @@
-use makedeb_srcinfo::{SplitDependency, SplitPackage};
+use makedeb_srcinfo::{compare_versions, SplitDependency, SplitPackage};
+use std::cmp::Ordering;
 
 #[test]
 fn split_version() {
@@ -29,3 +30,31 @@ fn split_dependency() {
     assert_eq!(ver2.as_control(), "pkg2 (= 1.0) | pkg4");
     assert_eq!(ver3.as_control(), "pkg3 (>= 1.0=1.3) | pkg5 (= 5) | pkg6");
 }
+
+#[test]
+fn version_comparison() {
+    assert_eq!(compare_versions("1.0", "1.0"), Ordering::Equal);
+    assert_eq!(compare_versions("1.0", "1.1"), Ordering::Less);
+    assert_eq!(compare_versions("1.1", "1.0"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0~rc1", "1.0"), Ordering::Less);
+    assert_eq!(compare_versions("1:1.0", "2.0"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0-1", "1.0"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0.9", "1.0.10"), Ordering::Less);
+
+    // The end of the string sorts below letters, so a letter suffix is newer.
+    assert_eq!(compare_versions("1.0", "1.0a"), Ordering::Less);
+    assert_eq!(compare_versions("1.0a", "1.0"), Ordering::Greater);
+    // ...but `~` still sorts below everything, including the end of the string.
+    assert_eq!(compare_versions("1.0~", "1.0"), Ordering::Less);
+}
+
+#[test]
+fn satisfied_by() {
+    let pkg = SplitPackage::new("pkg1>=1.0");
+    assert!(pkg.satisfied_by("1.0"));
+    assert!(pkg.satisfied_by("1.5"));
+    assert!(!pkg.satisfied_by("0.9"));
+
+    let unversioned = SplitPackage::new("pkg1");
+    assert!(unversioned.satisfied_by("0.1"));
+}